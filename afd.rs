@@ -135,6 +135,39 @@ fn print_num(n: i32) {
     print_str(s);
 }
 
+// Like print_num, but formats in an arbitrary base (2-36), using a-z for
+// digits above 9.
+fn print_num_in_base(n: i32, base: i32) {
+    let mut buf = [0u8; 34];
+    let mut i = buf.len();
+    let mut num = n;
+    let negative = num < 0;
+
+    if negative {
+        num = -num;
+    }
+
+    if num == 0 {
+        print_str("0");
+        return;
+    }
+
+    while num > 0 {
+        i -= 1;
+        let digit = (num % base) as u8;
+        buf[i] = if digit < 10 { digit + b'0' } else { digit - 10 + b'a' };
+        num /= base;
+    }
+
+    if negative {
+        i -= 1;
+        buf[i] = b'-';
+    }
+
+    let s = unsafe { core::str::from_utf8_unchecked(&buf[i..]) };
+    print_str(s);
+}
+
 fn read_char() -> Option<u8> {
     let mut buf = [0u8; 1];
     unsafe {
@@ -151,7 +184,7 @@ fn read_char() -> Option<u8> {
 const STACK_SIZE: usize = 64;
 const INPUT_BUFFER_SIZE: usize = 256;
 const WORD_BUFFER_SIZE: usize = 32;
-const DICTIONARY_SIZE: usize = 32;
+const DICTIONARY_SIZE: usize = 64;
 const MAX_WORD_NAME_LEN: usize = 16;
 const USER_WORDS_SIZE: usize = 1024;
 
@@ -197,6 +230,44 @@ impl ForthStack {
     }
 }
 
+struct ReturnStack {
+    data: [i32; RETURN_STACK_SIZE],
+    top: usize,
+}
+
+impl ReturnStack {
+    fn new() -> Self {
+        Self {
+            data: [0; RETURN_STACK_SIZE],
+            top: 0,
+        }
+    }
+
+    fn push(&mut self, value: i32) -> Result<(), &'static str> {
+        if self.top >= RETURN_STACK_SIZE {
+            return Err("Return stack overflow");
+        }
+        self.data[self.top] = value;
+        self.top += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Result<i32, &'static str> {
+        if self.top == 0 {
+            return Err("Return stack underflow");
+        }
+        self.top -= 1;
+        Ok(self.data[self.top])
+    }
+
+    fn peek(&self) -> Result<i32, &'static str> {
+        if self.top == 0 {
+            return Err("Return stack empty");
+        }
+        Ok(self.data[self.top - 1])
+    }
+}
+
 #[derive(Clone, Copy)]
 struct DictionaryEntry {
     name: [u8; MAX_WORD_NAME_LEN],
@@ -241,9 +312,86 @@ const BUILTIN_EQUAL: u8 = 17;
 const BUILTIN_LESS: u8 = 18;
 const BUILTIN_GREATER: u8 = 19;
 const BUILTIN_WORDS: u8 = 20;
+const BUILTIN_IF: u8 = 21;
+const BUILTIN_ELSE: u8 = 22;
+const BUILTIN_THEN: u8 = 23;
+const BUILTIN_BEGIN: u8 = 24;
+const BUILTIN_UNTIL: u8 = 25;
+const BUILTIN_AGAIN: u8 = 26;
+const BUILTIN_TO_R: u8 = 27;
+const BUILTIN_R_FROM: u8 = 28;
+const BUILTIN_R_FETCH: u8 = 29;
+const BUILTIN_DO: u8 = 30;
+const BUILTIN_LOOP: u8 = 31;
+const BUILTIN_PLUS_LOOP: u8 = 32;
+const BUILTIN_I: u8 = 33;
+const BUILTIN_VARIABLE: u8 = 34;
+const BUILTIN_CONSTANT: u8 = 35;
+const BUILTIN_FETCH: u8 = 36;
+const BUILTIN_STORE: u8 = 37;
+const BUILTIN_PLUS_STORE: u8 = 38;
+const BUILTIN_SEE: u8 = 39;
+const BUILTIN_HEX: u8 = 40;
+const BUILTIN_DECIMAL: u8 = 41;
+const BUILTIN_BINARY: u8 = 42;
+
+// Opcodes for the compiled token stream a user word's body is made of.
+// These share the `user_words` byte array but are a distinct namespace
+// from the BUILTIN_* ids above.
+const OP_CALL: u8 = 1;
+const OP_LIT: u8 = 2;
+const OP_BRANCH: u8 = 3;
+const OP_ZBRANCH: u8 = 4;
+const OP_DO: u8 = 5;
+const OP_LOOP: u8 = 6;
+const OP_PLUS_LOOP: u8 = 7;
+
+// Depth of the return stack used by >R/R>/R@ and DO/LOOP index bookkeeping.
+const RETURN_STACK_SIZE: usize = 64;
+
+// Flat cell-addressable data space for VARIABLE/CONSTANT.
+const DATA_SPACE_SIZE: usize = 256;
+// Added to a data-space cell index to form the address users see, so an
+// address is never confused with a small plain-integer literal.
+const DATA_SPACE_BASE: i32 = 0x1000;
+
+// What kind of defining word is waiting on the upcoming name token.
+const DEF_KIND_COLON: u8 = 0;
+const DEF_KIND_VARIABLE: u8 = 1;
+const DEF_KIND_CONSTANT: u8 = 2;
+
+// How deep `execute_user_word` may recurse before we bail instead of
+// risking the native stack.
+const MAX_CALL_DEPTH: usize = 64;
+
+// Depth of the compile-time control-flow stack (nested IF/BEGIN blocks).
+const CONTROL_STACK_SIZE: usize = 16;
+
+// A word is "immediate" if it runs while compiling instead of being
+// appended to the body as an OP_CALL.
+fn is_immediate(builtin_id: u8) -> bool {
+    matches!(
+        builtin_id,
+        BUILTIN_COLON
+            | BUILTIN_SEMICOLON
+            | BUILTIN_IF
+            | BUILTIN_ELSE
+            | BUILTIN_THEN
+            | BUILTIN_BEGIN
+            | BUILTIN_UNTIL
+            | BUILTIN_AGAIN
+            | BUILTIN_DO
+            | BUILTIN_LOOP
+            | BUILTIN_PLUS_LOOP
+            | BUILTIN_VARIABLE
+            | BUILTIN_CONSTANT
+            | BUILTIN_SEE
+    )
+}
 
 struct ForthInterpreter {
     stack: ForthStack,
+    return_stack: ReturnStack,
     input_buffer: [u8; INPUT_BUFFER_SIZE],
     word_buffer: [u8; WORD_BUFFER_SIZE],
     dictionary: [DictionaryEntry; DICTIONARY_SIZE],
@@ -252,12 +400,23 @@ struct ForthInterpreter {
     user_words_top: usize,
     compiling: bool,
     current_def_start: usize,
+    current_def_index: usize,
+    defining_name: bool,
+    current_def_kind: u8,
+    pending_constant_value: i32,
+    control_stack: [usize; CONTROL_STACK_SIZE],
+    control_top: usize,
+    data_space: [i32; DATA_SPACE_SIZE],
+    here: usize,
+    pending_see: bool,
+    base_cell: usize,
 }
 
 impl ForthInterpreter {
     fn new() -> Self {
         Self {
             stack: ForthStack::new(),
+            return_stack: ReturnStack::new(),
             input_buffer: [0; INPUT_BUFFER_SIZE],
             word_buffer: [0; WORD_BUFFER_SIZE],
             dictionary: [DictionaryEntry::new(); DICTIONARY_SIZE],
@@ -266,7 +425,170 @@ impl ForthInterpreter {
             user_words_top: 0,
             compiling: false,
             current_def_start: 0,
+            current_def_index: 0,
+            defining_name: false,
+            current_def_kind: DEF_KIND_COLON,
+            pending_constant_value: 0,
+            control_stack: [0; CONTROL_STACK_SIZE],
+            control_top: 0,
+            data_space: [0; DATA_SPACE_SIZE],
+            here: 0,
+            pending_see: false,
+            base_cell: 0,
+        }
+    }
+
+    // BASE is a plain user-writable data-space cell (`base !` stores
+    // through it like any other variable), so an out-of-range value must
+    // be caught here rather than trusted by the formatter/parser, which
+    // would otherwise divide or index by it directly.
+    fn current_base(&self) -> i32 {
+        let base = self.data_space[self.base_cell];
+        if (2..=36).contains(&base) {
+            base
+        } else {
+            10
+        }
+    }
+
+    // Allocates the BASE cell and its dictionary word, the same shape as
+    // a user VARIABLE: a body that pushes the cell's address.
+    fn init_base(&mut self) {
+        let addr = match self.allocate_cell() {
+            Ok(addr) => addr,
+            Err(_) => return,
+        };
+        let index = match self.data_index(addr) {
+            Ok(index) => index,
+            Err(_) => return,
+        };
+        self.data_space[index] = 10;
+        self.base_cell = index;
+
+        let start = self.user_words_top;
+        if self.compile_literal(addr).is_err() {
+            return;
+        }
+        if self.dict_top >= DICTIONARY_SIZE {
+            return;
+        }
+
+        let entry = &mut self.dictionary[self.dict_top];
+        let name = b"base";
+        for i in 0..name.len() {
+            entry.name[i] = name[i];
+        }
+        entry.name_len = name.len();
+        entry.is_builtin = false;
+        entry.user_word_start = start;
+        entry.user_word_len = self.user_words_top - start;
+        self.dict_top += 1;
+    }
+
+    // Bump-allocates one data-space cell and returns its encoded address.
+    fn allocate_cell(&mut self) -> Result<i32, &'static str> {
+        if self.here >= DATA_SPACE_SIZE {
+            return Err("Data space full");
+        }
+        let addr = DATA_SPACE_BASE + self.here as i32;
+        self.here += 1;
+        Ok(addr)
+    }
+
+    // Decodes an address into a data_space index, validating it refers to
+    // a cell that has actually been allocated.
+    fn data_index(&self, addr: i32) -> Result<usize, &'static str> {
+        let offset = addr - DATA_SPACE_BASE;
+        if offset < 0 || offset as usize >= self.here {
+            return Err("Address out of range");
+        }
+        Ok(offset as usize)
+    }
+
+    // Abort a definition in progress, discarding any partial dictionary
+    // entry and rewinding the user_words arena.
+    fn abort_compile(&mut self) {
+        self.compiling = false;
+        self.defining_name = false;
+        self.control_top = 0;
+        self.user_words_top = self.current_def_start;
+        if self.dict_top > 0 && !self.dictionary[self.dict_top - 1].is_builtin
+            && self.dictionary[self.dict_top - 1].user_word_start == self.current_def_start
+            && self.dictionary[self.dict_top - 1].user_word_len == 0
+        {
+            self.dict_top -= 1;
+        }
+    }
+
+    // Append one byte to the user_words arena, rolling back the
+    // definition in progress on overflow.
+    fn compile_byte(&mut self, byte: u8) -> Result<(), &'static str> {
+        if self.user_words_top >= USER_WORDS_SIZE {
+            self.abort_compile();
+            return Err("User words arena full");
+        }
+        self.user_words[self.user_words_top] = byte;
+        self.user_words_top += 1;
+        Ok(())
+    }
+
+    fn compile_literal(&mut self, value: i32) -> Result<(), &'static str> {
+        self.compile_byte(OP_LIT)?;
+        for byte in value.to_le_bytes() {
+            self.compile_byte(byte)?;
+        }
+        Ok(())
+    }
+
+    fn compile_call(&mut self, dict_index: usize) -> Result<(), &'static str> {
+        if dict_index >= 256 {
+            self.abort_compile();
+            return Err("Dictionary index too large to encode");
+        }
+        self.compile_byte(OP_CALL)?;
+        self.compile_byte(dict_index as u8)
+    }
+
+    // Emits a branch opcode with a placeholder i16 offset and returns the
+    // position of the opcode byte, to be patched later.
+    fn compile_branch(&mut self, op: u8) -> Result<usize, &'static str> {
+        let pos = self.user_words_top;
+        self.compile_byte(op)?;
+        self.compile_byte(0)?;
+        self.compile_byte(0)?;
+        Ok(pos)
+    }
+
+    // Patches the branch at `pos` to jump to `target`, both being
+    // absolute offsets into `user_words`.
+    fn patch_branch(&mut self, pos: usize, target: usize) -> Result<(), &'static str> {
+        let offset = target as isize - pos as isize;
+        if offset < i16::MIN as isize || offset > i16::MAX as isize {
+            self.abort_compile();
+            return Err("Branch offset too large");
+        }
+        let bytes = (offset as i16).to_le_bytes();
+        self.user_words[pos + 1] = bytes[0];
+        self.user_words[pos + 2] = bytes[1];
+        Ok(())
+    }
+
+    fn control_push(&mut self, pos: usize) -> Result<(), &'static str> {
+        if self.control_top >= CONTROL_STACK_SIZE {
+            self.abort_compile();
+            return Err("Control stack overflow");
+        }
+        self.control_stack[self.control_top] = pos;
+        self.control_top += 1;
+        Ok(())
+    }
+
+    fn control_pop(&mut self) -> Result<usize, &'static str> {
+        if self.control_top == 0 {
+            return Err("Unstructured control flow");
         }
+        self.control_top -= 1;
+        Ok(self.control_stack[self.control_top])
     }
     
     fn init_builtins(&mut self) {
@@ -290,6 +612,29 @@ impl ForthInterpreter {
         self.add_builtin(b"<", BUILTIN_LESS);
         self.add_builtin(b">", BUILTIN_GREATER);
         self.add_builtin(b"words", BUILTIN_WORDS);
+        self.add_builtin(b"if", BUILTIN_IF);
+        self.add_builtin(b"else", BUILTIN_ELSE);
+        self.add_builtin(b"then", BUILTIN_THEN);
+        self.add_builtin(b"begin", BUILTIN_BEGIN);
+        self.add_builtin(b"until", BUILTIN_UNTIL);
+        self.add_builtin(b"again", BUILTIN_AGAIN);
+        self.add_builtin(b">r", BUILTIN_TO_R);
+        self.add_builtin(b"r>", BUILTIN_R_FROM);
+        self.add_builtin(b"r@", BUILTIN_R_FETCH);
+        self.add_builtin(b"do", BUILTIN_DO);
+        self.add_builtin(b"loop", BUILTIN_LOOP);
+        self.add_builtin(b"+loop", BUILTIN_PLUS_LOOP);
+        self.add_builtin(b"i", BUILTIN_I);
+        self.add_builtin(b"variable", BUILTIN_VARIABLE);
+        self.add_builtin(b"constant", BUILTIN_CONSTANT);
+        self.add_builtin(b"@", BUILTIN_FETCH);
+        self.add_builtin(b"!", BUILTIN_STORE);
+        self.add_builtin(b"+!", BUILTIN_PLUS_STORE);
+        self.add_builtin(b"see", BUILTIN_SEE);
+        self.add_builtin(b"hex", BUILTIN_HEX);
+        self.add_builtin(b"decimal", BUILTIN_DECIMAL);
+        self.add_builtin(b"binary", BUILTIN_BINARY);
+        self.init_base();
     }
     
     fn add_builtin(&mut self, name: &[u8], id: u8) {
@@ -373,15 +718,16 @@ impl ForthInterpreter {
             }
             BUILTIN_DOT => {
                 let value = self.stack.pop()?;
-                print_num(value);
+                print_num_in_base(value, self.current_base());
                 print_str(" ");
             }
             BUILTIN_DOTS => {
                 print_str("<");
                 print_num(self.stack.size() as i32);
                 print_str("> ");
+                let base = self.current_base();
                 for i in 0..self.stack.size() {
-                    print_num(self.stack.data[i]);
+                    print_num_in_base(self.stack.data[i], base);
                     print_str(" ");
                 }
             }
@@ -397,12 +743,23 @@ impl ForthInterpreter {
                     return Err("Already compiling");
                 }
                 self.compiling = true;
+                self.defining_name = true;
                 self.current_def_start = self.user_words_top;
+                self.current_def_kind = DEF_KIND_COLON;
             }
             BUILTIN_SEMICOLON => {
                 if !self.compiling {
                     return Err("Not compiling");
                 }
+                if self.defining_name {
+                    return Err("Definition has no name");
+                }
+                if self.control_top != 0 {
+                    self.abort_compile();
+                    return Err("Unstructured control flow");
+                }
+                self.dictionary[self.current_def_index].user_word_len =
+                    self.user_words_top - self.current_def_start;
                 self.compiling = false;
             }
             BUILTIN_EQUAL => {
@@ -420,6 +777,138 @@ impl ForthInterpreter {
                 let a = self.stack.pop()?;
                 self.stack.push(if a > b { -1 } else { 0 })?;
             }
+            BUILTIN_IF => {
+                if !self.compiling {
+                    return Err("IF outside a definition");
+                }
+                let pos = self.compile_branch(OP_ZBRANCH)?;
+                self.control_push(pos)?;
+            }
+            BUILTIN_ELSE => {
+                if !self.compiling {
+                    return Err("ELSE outside a definition");
+                }
+                let if_pos = self.control_pop()?;
+                let else_pos = self.compile_branch(OP_BRANCH)?;
+                self.patch_branch(if_pos, self.user_words_top)?;
+                self.control_push(else_pos)?;
+            }
+            BUILTIN_THEN => {
+                if !self.compiling {
+                    return Err("THEN outside a definition");
+                }
+                let pos = self.control_pop()?;
+                self.patch_branch(pos, self.user_words_top)?;
+            }
+            BUILTIN_BEGIN => {
+                if !self.compiling {
+                    return Err("BEGIN outside a definition");
+                }
+                self.control_push(self.user_words_top)?;
+            }
+            BUILTIN_UNTIL => {
+                if !self.compiling {
+                    return Err("UNTIL outside a definition");
+                }
+                let begin_pos = self.control_pop()?;
+                let pos = self.compile_branch(OP_ZBRANCH)?;
+                self.patch_branch(pos, begin_pos)?;
+            }
+            BUILTIN_AGAIN => {
+                if !self.compiling {
+                    return Err("AGAIN outside a definition");
+                }
+                let begin_pos = self.control_pop()?;
+                let pos = self.compile_branch(OP_BRANCH)?;
+                self.patch_branch(pos, begin_pos)?;
+            }
+            BUILTIN_TO_R => {
+                let value = self.stack.pop()?;
+                self.return_stack.push(value)?;
+            }
+            BUILTIN_R_FROM => {
+                let value = self.return_stack.pop()?;
+                self.stack.push(value)?;
+            }
+            BUILTIN_R_FETCH | BUILTIN_I => {
+                let value = self.return_stack.peek()?;
+                self.stack.push(value)?;
+            }
+            BUILTIN_DO => {
+                if !self.compiling {
+                    return Err("DO outside a definition");
+                }
+                self.compile_byte(OP_DO)?;
+                self.control_push(self.user_words_top)?;
+            }
+            BUILTIN_LOOP => {
+                if !self.compiling {
+                    return Err("LOOP outside a definition");
+                }
+                let head = self.control_pop()?;
+                let pos = self.compile_branch(OP_LOOP)?;
+                self.patch_branch(pos, head)?;
+            }
+            BUILTIN_PLUS_LOOP => {
+                if !self.compiling {
+                    return Err("+LOOP outside a definition");
+                }
+                let head = self.control_pop()?;
+                let pos = self.compile_branch(OP_PLUS_LOOP)?;
+                self.patch_branch(pos, head)?;
+            }
+            BUILTIN_VARIABLE => {
+                if self.compiling {
+                    return Err("VARIABLE inside a definition");
+                }
+                self.compiling = true;
+                self.defining_name = true;
+                self.current_def_start = self.user_words_top;
+                self.current_def_kind = DEF_KIND_VARIABLE;
+            }
+            BUILTIN_CONSTANT => {
+                if self.compiling {
+                    return Err("CONSTANT inside a definition");
+                }
+                let value = self.stack.pop()?;
+                self.compiling = true;
+                self.defining_name = true;
+                self.current_def_start = self.user_words_top;
+                self.current_def_kind = DEF_KIND_CONSTANT;
+                self.pending_constant_value = value;
+            }
+            BUILTIN_FETCH => {
+                let addr = self.stack.pop()?;
+                let index = self.data_index(addr)?;
+                self.stack.push(self.data_space[index])?;
+            }
+            BUILTIN_STORE => {
+                let addr = self.stack.pop()?;
+                let value = self.stack.pop()?;
+                let index = self.data_index(addr)?;
+                self.data_space[index] = value;
+            }
+            BUILTIN_PLUS_STORE => {
+                let addr = self.stack.pop()?;
+                let delta = self.stack.pop()?;
+                let index = self.data_index(addr)?;
+                self.data_space[index] += delta;
+            }
+            BUILTIN_SEE => {
+                if self.compiling {
+                    return Err("SEE inside a definition");
+                }
+                self.pending_see = true;
+            }
+            BUILTIN_HEX => {
+                self.data_space[self.base_cell] = 16;
+            }
+            BUILTIN_DECIMAL => {
+                self.data_space[self.base_cell] = 10;
+            }
+            BUILTIN_BINARY => {
+                self.data_space[self.base_cell] = 2;
+            }
             BUILTIN_WORDS => {
                 print_str("Words: ");
                 for i in 0..self.dict_top {
@@ -461,15 +950,18 @@ impl ForthInterpreter {
         true
     }
     
-    fn parse_number(word: &[u8]) -> Option<i32> {
+    // Parses a number in the current BASE, honoring a leading '-' sign
+    // and a one-token prefix ($ or 0x forces hex, % forces binary)
+    // regardless of the current base.
+    fn parse_number(&self, word: &[u8]) -> Option<i32> {
         if word.is_empty() {
             return None;
         }
-        
-        let mut result = 0i32;
-        let mut negative = false;
+
+        let mut base = self.current_base();
         let mut start = 0;
-        
+        let mut negative = false;
+
         if word[0] == b'-' {
             negative = true;
             start = 1;
@@ -477,18 +969,41 @@ impl ForthInterpreter {
                 return None;
             }
         }
-        
+
+        let rest = &word[start..];
+        if rest.len() >= 2 && rest[0] == b'0' && (rest[1] == b'x' || rest[1] == b'X') {
+            base = 16;
+            start += 2;
+        } else if rest[0] == b'$' {
+            base = 16;
+            start += 1;
+        } else if rest[0] == b'%' {
+            base = 2;
+            start += 1;
+        }
+
+        if start >= word.len() {
+            return None;
+        }
+
+        let mut result = 0i32;
         for &byte in &word[start..] {
-            if byte < b'0' || byte > b'9' {
+            let digit = match byte {
+                b'0'..=b'9' => (byte - b'0') as i32,
+                b'a'..=b'z' => (byte - b'a') as i32 + 10,
+                b'A'..=b'Z' => (byte - b'A') as i32 + 10,
+                _ => return None,
+            };
+            if digit >= base {
                 return None;
             }
-            result = result * 10 + (byte - b'0') as i32;
+            result = result * base + digit;
         }
-        
+
         if negative {
             result = -result;
         }
-        
+
         Some(result)
     }
     
@@ -516,54 +1031,338 @@ impl ForthInterpreter {
         None
     }
     
+    // Runs a user word's compiled body: an inner interpreter over the
+    // OP_* token stream (calls, literals, branches and loop opcodes) stored
+    // in `user_words[start..start+len]`.
+    fn execute_user_word(
+        &mut self,
+        start: usize,
+        len: usize,
+        depth: usize,
+    ) -> Result<bool, &'static str> {
+        if depth >= MAX_CALL_DEPTH {
+            return Err("Recursion too deep");
+        }
+
+        let end = start + len;
+        let mut ip = start;
+
+        while ip < end {
+            let op_pos = ip;
+            let op = self.user_words[ip];
+            ip += 1;
+
+            match op {
+                OP_CALL => {
+                    if ip >= end {
+                        return Err("Truncated definition");
+                    }
+                    let index = self.user_words[ip] as usize;
+                    ip += 1;
+
+                    let entry = self.dictionary[index];
+                    let should_exit = if entry.is_builtin {
+                        self.execute_builtin(entry.builtin_id)?
+                    } else {
+                        self.execute_user_word(entry.user_word_start, entry.user_word_len, depth + 1)?
+                    };
+                    if should_exit {
+                        return Ok(true);
+                    }
+                }
+                OP_LIT => {
+                    if ip + 4 > end {
+                        return Err("Truncated definition");
+                    }
+                    let bytes = [
+                        self.user_words[ip],
+                        self.user_words[ip + 1],
+                        self.user_words[ip + 2],
+                        self.user_words[ip + 3],
+                    ];
+                    ip += 4;
+                    self.stack.push(i32::from_le_bytes(bytes))?;
+                }
+                OP_BRANCH => {
+                    if ip + 2 > end {
+                        return Err("Truncated definition");
+                    }
+                    let offset = i16::from_le_bytes([self.user_words[ip], self.user_words[ip + 1]]);
+                    ip = (op_pos as isize + offset as isize) as usize;
+                }
+                OP_ZBRANCH => {
+                    if ip + 2 > end {
+                        return Err("Truncated definition");
+                    }
+                    let offset = i16::from_le_bytes([self.user_words[ip], self.user_words[ip + 1]]);
+                    let cond = self.stack.pop()?;
+                    if cond == 0 {
+                        ip = (op_pos as isize + offset as isize) as usize;
+                    } else {
+                        ip += 2;
+                    }
+                }
+                OP_DO => {
+                    let start_index = self.stack.pop()?;
+                    let limit = self.stack.pop()?;
+                    self.return_stack.push(limit)?;
+                    self.return_stack.push(start_index)?;
+                }
+                OP_LOOP => {
+                    if ip + 2 > end {
+                        return Err("Truncated definition");
+                    }
+                    let offset = i16::from_le_bytes([self.user_words[ip], self.user_words[ip + 1]]);
+                    let index = self.return_stack.pop()?;
+                    let limit = self.return_stack.pop()?;
+                    let new_index = index + 1;
+                    if new_index < limit {
+                        self.return_stack.push(limit)?;
+                        self.return_stack.push(new_index)?;
+                        ip = (op_pos as isize + offset as isize) as usize;
+                    } else {
+                        ip += 2;
+                    }
+                }
+                OP_PLUS_LOOP => {
+                    if ip + 2 > end {
+                        return Err("Truncated definition");
+                    }
+                    let offset = i16::from_le_bytes([self.user_words[ip], self.user_words[ip + 1]]);
+                    let step = self.stack.pop()?;
+                    let index = self.return_stack.pop()?;
+                    let limit = self.return_stack.pop()?;
+                    let new_index = index + step;
+                    let crossed = if step >= 0 {
+                        new_index >= limit
+                    } else {
+                        new_index <= limit
+                    };
+                    if !crossed {
+                        self.return_stack.push(limit)?;
+                        self.return_stack.push(new_index)?;
+                        ip = (op_pos as isize + offset as isize) as usize;
+                    } else {
+                        ip += 2;
+                    }
+                }
+                _ => return Err("Bad opcode"),
+            }
+        }
+
+        Ok(false)
+    }
+
+    // Decodes and prints a user word's compiled body for `SEE name`.
+    fn see_word(&mut self, word: &[u8]) {
+        let index = match self.find_word(word) {
+            Some(index) => index,
+            None => {
+                print_str("Unknown word: ");
+                let word_str = unsafe { core::str::from_utf8_unchecked(word) };
+                print_str(word_str);
+                print_str("\n");
+                return;
+            }
+        };
+
+        let entry = self.dictionary[index];
+        let name = unsafe { core::str::from_utf8_unchecked(&entry.name[..entry.name_len]) };
+
+        print_str(": ");
+        print_str(name);
+
+        if entry.is_builtin {
+            print_str("  <builtin> ;\n");
+            return;
+        }
+
+        let start = entry.user_word_start;
+        let end = start + entry.user_word_len;
+        let mut ip = start;
+
+        while ip < end {
+            let op = self.user_words[ip];
+            ip += 1;
+
+            match op {
+                OP_LIT => {
+                    if ip + 4 > end {
+                        print_str(" truncated definition");
+                        break;
+                    }
+                    let bytes = [
+                        self.user_words[ip],
+                        self.user_words[ip + 1],
+                        self.user_words[ip + 2],
+                        self.user_words[ip + 3],
+                    ];
+                    ip += 4;
+                    print_str(" ");
+                    print_num(i32::from_le_bytes(bytes));
+                }
+                OP_CALL => {
+                    if ip >= end {
+                        print_str(" truncated definition");
+                        break;
+                    }
+                    let callee = self.user_words[ip] as usize;
+                    ip += 1;
+                    let callee_entry = self.dictionary[callee];
+                    let callee_name = unsafe {
+                        core::str::from_utf8_unchecked(&callee_entry.name[..callee_entry.name_len])
+                    };
+                    print_str(" CALL ");
+                    print_str(callee_name);
+                }
+                OP_BRANCH | OP_ZBRANCH | OP_LOOP | OP_PLUS_LOOP => {
+                    if ip + 2 > end {
+                        print_str(" truncated definition");
+                        break;
+                    }
+                    let offset =
+                        i16::from_le_bytes([self.user_words[ip], self.user_words[ip + 1]]);
+                    ip += 2;
+                    print_str(" ");
+                    print_str(match op {
+                        OP_BRANCH => "BRANCH",
+                        OP_ZBRANCH => "ZBRANCH",
+                        OP_LOOP => "LOOP",
+                        _ => "+LOOP",
+                    });
+                    print_str(" ");
+                    print_num(offset as i32);
+                }
+                OP_DO => {
+                    print_str(" DO");
+                }
+                _ => {
+                    print_str(" <bad opcode>");
+                    break;
+                }
+            }
+        }
+
+        print_str(" ;\n");
+    }
+
     fn execute_word(&mut self, word: &[u8]) -> Result<bool, &'static str> {
-        // Try to parse as number first
-        if let Some(num) = Self::parse_number(word) {
-            self.stack.push(num)?;
+        if self.pending_see {
+            self.pending_see = false;
+            self.see_word(word);
             return Ok(false);
         }
-        
-        // Look up word in dictionary
+
+        if self.compiling && self.defining_name {
+            // The first word after `:`/VARIABLE/CONSTANT names the
+            // definition, not a call.
+            if self.dict_top >= DICTIONARY_SIZE {
+                self.abort_compile();
+                return Err("Dictionary full");
+            }
+
+            let entry = &mut self.dictionary[self.dict_top];
+            let len = core::cmp::min(word.len(), MAX_WORD_NAME_LEN);
+
+            for i in 0..len {
+                entry.name[i] = word[i];
+            }
+            entry.name_len = len;
+            entry.is_builtin = false;
+            entry.user_word_start = self.current_def_start;
+            entry.user_word_len = 0;
+
+            self.current_def_index = self.dict_top;
+            self.dict_top += 1;
+            self.defining_name = false;
+
+            match self.current_def_kind {
+                DEF_KIND_VARIABLE => {
+                    let addr = match self.allocate_cell() {
+                        Ok(addr) => addr,
+                        Err(e) => {
+                            self.abort_compile();
+                            return Err(e);
+                        }
+                    };
+                    if let Err(e) = self.compile_literal(addr) {
+                        return Err(e);
+                    }
+                    self.dictionary[self.current_def_index].user_word_len =
+                        self.user_words_top - self.current_def_start;
+                    self.compiling = false;
+                }
+                DEF_KIND_CONSTANT => {
+                    let value = self.pending_constant_value;
+                    if let Err(e) = self.compile_literal(value) {
+                        return Err(e);
+                    }
+                    self.dictionary[self.current_def_index].user_word_len =
+                        self.user_words_top - self.current_def_start;
+                    self.compiling = false;
+                }
+                _ => {}
+            }
+
+            return Ok(false);
+        }
+
+        // Immediate words (see `is_immediate`: `:`, `;`, the control-flow
+        // words, VARIABLE, CONSTANT and SEE) run even while compiling.
+        // Every other word is compiled into the body instead of being
+        // executed, while we're compiling.
+        if self.compiling {
+            if let Some(index) = self.find_word(word) {
+                let entry = self.dictionary[index];
+                if entry.is_builtin && is_immediate(entry.builtin_id) {
+                    return self.execute_builtin(entry.builtin_id);
+                }
+                self.compile_call(index)?;
+                return Ok(false);
+            }
+            if let Some(num) = self.parse_number(word) {
+                self.compile_literal(num)?;
+                return Ok(false);
+            }
+            self.abort_compile();
+            print_str("Unknown word: ");
+            let word_str = unsafe { core::str::from_utf8_unchecked(word) };
+            print_str(word_str);
+            print_str("\n");
+            return Err("Unknown word");
+        }
+
+        // Look up word in dictionary first, matching the compile path:
+        // a defined word always wins over a number, even if its name
+        // also happens to parse as a digit string in the current BASE.
         if let Some(index) = self.find_word(word) {
             let entry = self.dictionary[index];
-            
+
             if entry.is_builtin {
                 let should_exit = self.execute_builtin(entry.builtin_id)?;
                 if should_exit {
                     return Ok(true);
                 }
-            }
-        } else {
-            if self.compiling {
-                // This must be the name of the word being defined
-                if self.dict_top >= DICTIONARY_SIZE {
-                    return Err("Dictionary full");
-                }
-                
-                let entry = &mut self.dictionary[self.dict_top];
-                let len = core::cmp::min(word.len(), MAX_WORD_NAME_LEN);
-                
-                for i in 0..len {
-                    entry.name[i] = word[i];
-                }
-                entry.name_len = len;
-                entry.is_builtin = false;
-                entry.user_word_start = self.current_def_start;
-                entry.user_word_len = self.user_words_top - self.current_def_start;
-                
-                self.dict_top += 1;
             } else {
-                print_str("Unknown word: ");
-                let word_str = unsafe { core::str::from_utf8_unchecked(word) };
-                print_str(word_str);
-                print_str("\n");
-                return Err("Unknown word");
+                let should_exit =
+                    self.execute_user_word(entry.user_word_start, entry.user_word_len, 0)?;
+                if should_exit {
+                    return Ok(true);
+                }
             }
+        } else if let Some(num) = self.parse_number(word) {
+            self.stack.push(num)?;
+        } else {
+            print_str("Unknown word: ");
+            let word_str = unsafe { core::str::from_utf8_unchecked(word) };
+            print_str(word_str);
+            print_str("\n");
+            return Err("Unknown word");
         }
-        
+
         Ok(false)
     }
-    
+
     fn process_line(&mut self) -> Result<bool, &'static str> {
         let mut pos = 0;
         
@@ -620,7 +1419,11 @@ impl ForthInterpreter {
         print_str("afd: Alien Forth Dialect v0.4\n");
         print_str("Type 'bye' to exit, '.s' to show stack, 'words' to list words\n");
         print_str("Available: + - * / mod dup drop swap over rot . .s cr bye\n");
-        print_str("           = < > words : ;\n\n");
+        print_str("           = < > words : ;\n");
+        print_str("           if else then begin until again\n");
+        print_str("           >r r> r@ do loop +loop i\n");
+        print_str("           variable constant @ ! +!\n");
+        print_str("           see hex decimal binary base\n\n");
         
         loop {
             if self.compiling {
@@ -648,8 +1451,7 @@ impl ForthInterpreter {
                     print_str("\n");
                     if self.compiling {
                         print_str("Compilation aborted\n");
-                        self.compiling = false;
-                        self.user_words_top = self.current_def_start;
+                        self.abort_compile();
                     }
                 }
             }
@@ -58,52 +58,192 @@ pub unsafe extern "C" fn memcmp(s1: *const u8, s2: *const u8, n: usize) -> i32 {
     0
 }
 
-// Basic syscall interface for Linux x86_64
-#[cfg(target_arch = "x86_64")]
-mod syscalls {
-    pub const SYS_READ: usize = 0;
-    pub const SYS_WRITE: usize = 1;
-    pub const SYS_EXIT: usize = 60;
-    pub const STDIN_FILENO: usize = 0;
-    pub const STDOUT_FILENO: usize = 1;
-
-    #[inline]
-    pub unsafe fn syscall1(n: usize, a1: usize) -> isize {
-        let ret: isize;
-        core::arch::asm!(
-            "syscall",
-            in("rax") n,
-            in("rdi") a1,
-            out("rcx") _,
-            out("r11") _,
-            lateout("rax") ret,
-            options(nostack, preserves_flags)
-        );
-        ret
-    }
-
-    #[inline]
-    pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> isize {
-        let ret: isize;
-        core::arch::asm!(
-            "syscall",
-            in("rax") n,
-            in("rdi") a1,
-            in("rsi") a2,
-            in("rdx") a3,
-            out("rcx") _,
-            out("r11") _,
-            lateout("rax") ret,
-            options(nostack, preserves_flags)
-        );
-        ret
+// Platform abstraction: every target supplies the same three raw
+// operations (read/write/exit) with its own call-number table and trap
+// instruction. Everything above this module only calls `sys_read`,
+// `sys_write`, and `sys_exit` and never touches arch details directly,
+// the way std splits per-target syscalls under sys/*.
+mod platform {
+    #[cfg(target_arch = "x86_64")]
+    mod arch {
+        pub(super) const SYS_READ: usize = 0;
+        pub(super) const SYS_WRITE: usize = 1;
+        pub(super) const SYS_EXIT: usize = 60;
+
+        #[inline]
+        pub(super) unsafe fn syscall1(n: usize, a1: usize) -> isize {
+            let ret: isize;
+            core::arch::asm!(
+                "syscall",
+                in("rax") n,
+                in("rdi") a1,
+                out("rcx") _,
+                out("r11") _,
+                lateout("rax") ret,
+                options(nostack, preserves_flags)
+            );
+            ret
+        }
+
+        #[inline]
+        pub(super) unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> isize {
+            let ret: isize;
+            core::arch::asm!(
+                "syscall",
+                in("rax") n,
+                in("rdi") a1,
+                in("rsi") a2,
+                in("rdx") a3,
+                out("rcx") _,
+                out("r11") _,
+                lateout("rax") ret,
+                options(nostack, preserves_flags)
+            );
+            ret
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    mod arch {
+        pub(super) const SYS_READ: usize = 63;
+        pub(super) const SYS_WRITE: usize = 64;
+        pub(super) const SYS_EXIT: usize = 93;
+
+        #[inline]
+        pub(super) unsafe fn syscall1(n: usize, a1: usize) -> isize {
+            let ret: isize;
+            core::arch::asm!(
+                "svc #0",
+                in("x8") n,
+                in("x0") a1,
+                lateout("x0") ret,
+                options(nostack)
+            );
+            ret
+        }
+
+        #[inline]
+        pub(super) unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> isize {
+            let ret: isize;
+            core::arch::asm!(
+                "svc #0",
+                in("x8") n,
+                in("x0") a1,
+                in("x1") a2,
+                in("x2") a3,
+                lateout("x0") ret,
+                options(nostack)
+            );
+            ret
+        }
+    }
+
+    const STDIN_FILENO: usize = 0;
+    const STDOUT_FILENO: usize = 1;
+
+    pub fn sys_read(buf: &mut [u8]) -> isize {
+        unsafe {
+            arch::syscall3(
+                arch::SYS_READ,
+                STDIN_FILENO,
+                buf.as_mut_ptr() as usize,
+                buf.len(),
+            )
+        }
     }
+
+    pub fn sys_write(buf: &[u8]) -> isize {
+        unsafe { arch::syscall3(arch::SYS_WRITE, STDOUT_FILENO, buf.as_ptr() as usize, buf.len()) }
+    }
+
+    pub fn sys_exit(code: i32) -> ! {
+        unsafe {
+            arch::syscall1(arch::SYS_EXIT, code as usize);
+        }
+        loop {}
+    }
+}
+
+// Buffered I/O: batch bytes into a fixed fill/flush buffer instead of
+// issuing one syscall per byte or fragment, the way std's io::buffered
+// wraps a raw reader/writer in a BufReader/BufWriter.
+const READ_BUF_SIZE: usize = 512;
+const WRITE_BUF_SIZE: usize = 512;
+
+struct BufReader {
+    buf: [u8; READ_BUF_SIZE],
+    pos: usize,
+    cap: usize,
 }
 
+impl BufReader {
+    const fn new() -> Self {
+        Self {
+            buf: [0; READ_BUF_SIZE],
+            pos: 0,
+            cap: 0,
+        }
+    }
+
+    // Refills from stdin with a single syscall when the buffer is empty.
+    fn read_byte(&mut self) -> Option<u8> {
+        if self.pos >= self.cap {
+            let n = platform::sys_read(&mut self.buf);
+            if n <= 0 {
+                return None;
+            }
+            self.cap = n as usize;
+            self.pos = 0;
+        }
+
+        let byte = self.buf[self.pos];
+        self.pos += 1;
+        Some(byte)
+    }
+}
+
+struct BufWriter {
+    buf: [u8; WRITE_BUF_SIZE],
+    len: usize,
+}
+
+impl BufWriter {
+    const fn new() -> Self {
+        Self {
+            buf: [0; WRITE_BUF_SIZE],
+            len: 0,
+        }
+    }
+
+    // Line-buffered: flushes on newline, or when the fill buffer is full.
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            if self.len >= WRITE_BUF_SIZE {
+                self.flush();
+            }
+            self.buf[self.len] = byte;
+            self.len += 1;
+            if byte == b'\n' {
+                self.flush();
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.len > 0 {
+            platform::sys_write(&self.buf[..self.len]);
+            self.len = 0;
+        }
+    }
+}
+
+static mut STDIN_READER: BufReader = BufReader::new();
+static mut STDOUT_WRITER: BufWriter = BufWriter::new();
+
 // Simple print functions
 fn print_str(s: &str) {
     unsafe {
-        syscalls::syscall3(syscalls::SYS_WRITE, syscalls::STDOUT_FILENO, s.as_ptr() as usize, s.len());
+        (*core::ptr::addr_of_mut!(STDOUT_WRITER)).write_bytes(s.as_bytes());
     }
 }
 
@@ -112,40 +252,39 @@ fn print_num(n: i32) {
     let mut i = buf.len();
     let mut num = n;
     let negative = num < 0;
-    
+
     if negative {
         num = -num;
     }
-    
+
     if num == 0 {
         print_str("0");
         return;
     }
-    
+
     while num > 0 {
         i -= 1;
         buf[i] = (num % 10) as u8 + b'0';
         num /= 10;
     }
-    
+
     if negative {
         i -= 1;
         buf[i] = b'-';
     }
-    
+
     let s = unsafe { core::str::from_utf8_unchecked(&buf[i..]) };
     print_str(s);
 }
 
 fn read_char() -> Option<u8> {
-    let mut buf = [0u8; 1];
+    unsafe { (*core::ptr::addr_of_mut!(STDIN_READER)).read_byte() }
+}
+
+// Forces the prompt out even though it has no trailing newline.
+fn flush_output() {
     unsafe {
-        let result = syscalls::syscall3(syscalls::SYS_READ, syscalls::STDIN_FILENO, buf.as_mut_ptr() as usize, 1);
-        if result == 1 {
-            Some(buf[0])
-        } else {
-            None
-        }
+        (*core::ptr::addr_of_mut!(STDOUT_WRITER)).flush();
     }
 }
 
@@ -196,21 +335,371 @@ impl ForthStack {
     }
 }
 
+// No-alloc dictionary for colon definitions: a shared names arena holds
+// every word's name bytes, and a shared token code arena holds every
+// word's compiled body, with each Entry just recording offsets into them.
+const NAMES_ARENA_SIZE: usize = 512;
+const CODE_ARENA_SIZE: usize = 512;
+const DICTIONARY_SIZE: usize = 32;
+const CALL_STACK_SIZE: usize = 32;
+const CONTROL_STACK_SIZE: usize = 16;
+
+#[derive(Clone, Copy)]
+enum BuiltinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Dup,
+    Drop,
+    Swap,
+    Over,
+    Rot,
+    Dot,
+    DotS,
+    Cr,
+    Bye,
+    I,
+}
+
+fn lookup_builtin(word: &[u8]) -> Option<BuiltinOp> {
+    Some(match word {
+        b"+" => BuiltinOp::Add,
+        b"-" => BuiltinOp::Sub,
+        b"*" => BuiltinOp::Mul,
+        b"/" => BuiltinOp::Div,
+        b"mod" => BuiltinOp::Mod,
+        b"dup" => BuiltinOp::Dup,
+        b"drop" => BuiltinOp::Drop,
+        b"swap" => BuiltinOp::Swap,
+        b"over" => BuiltinOp::Over,
+        b"rot" => BuiltinOp::Rot,
+        b"." => BuiltinOp::Dot,
+        b".s" => BuiltinOp::DotS,
+        b"cr" => BuiltinOp::Cr,
+        b"bye" => BuiltinOp::Bye,
+        b"i" => BuiltinOp::I,
+        _ => return None,
+    })
+}
+
+// Words that only make sense while compiling a definition; checked by
+// name rather than through lookup_builtin since they drive the compiler
+// itself instead of compiling down to a token.
+fn is_control_word(word: &[u8]) -> bool {
+    matches!(
+        word,
+        b"if" | b"else" | b"then" | b"begin" | b"until" | b"do" | b"loop"
+    )
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    Literal(i32),
+    Builtin(BuiltinOp),
+    Call(usize),
+    Branch(usize),
+    ZeroBranch(usize),
+    Do,
+    Loop(usize),
+}
+
+#[derive(Clone, Copy)]
+struct Entry {
+    name_start: usize,
+    name_len: usize,
+    code_start: usize,
+    code_len: usize,
+}
+
 struct ForthInterpreter {
     stack: ForthStack,
+    return_stack: ForthStack,
     input_buffer: [u8; INPUT_BUFFER_SIZE],
     word_buffer: [u8; WORD_BUFFER_SIZE],
+    names_arena: [u8; NAMES_ARENA_SIZE],
+    names_top: usize,
+    code_arena: [Token; CODE_ARENA_SIZE],
+    code_top: usize,
+    dictionary: [Entry; DICTIONARY_SIZE],
+    dict_top: usize,
+    compiling: bool,
+    defining_name: bool,
+    current_def_code_start: usize,
+    current_def_index: usize,
+    control_stack: [usize; CONTROL_STACK_SIZE],
+    control_top: usize,
 }
 
 impl ForthInterpreter {
     fn new() -> Self {
         Self {
             stack: ForthStack::new(),
+            return_stack: ForthStack::new(),
             input_buffer: [0; INPUT_BUFFER_SIZE],
             word_buffer: [0; WORD_BUFFER_SIZE],
+            names_arena: [0; NAMES_ARENA_SIZE],
+            names_top: 0,
+            code_arena: [Token::Literal(0); CODE_ARENA_SIZE],
+            code_top: 0,
+            dictionary: [Entry {
+                name_start: 0,
+                name_len: 0,
+                code_start: 0,
+                code_len: 0,
+            }; DICTIONARY_SIZE],
+            dict_top: 0,
+            compiling: false,
+            defining_name: false,
+            current_def_code_start: 0,
+            current_def_index: 0,
+            control_stack: [0; CONTROL_STACK_SIZE],
+            control_top: 0,
         }
     }
-    
+
+    // Discards a definition in progress: rewinds the code arena and drops
+    // the partial dictionary entry it was writing into.
+    fn abort_compile(&mut self) {
+        self.compiling = false;
+        self.defining_name = false;
+        self.code_top = self.current_def_code_start;
+        self.control_top = 0;
+        if self.dict_top > 0
+            && self.dictionary[self.dict_top - 1].code_start == self.current_def_code_start
+            && self.dictionary[self.dict_top - 1].code_len == 0
+        {
+            self.dict_top -= 1;
+        }
+    }
+
+    // Fixed-size stack of pending branch-patch locations (IF/ELSE) or
+    // backward jump targets (BEGIN/DO), used only while compiling.
+    fn control_push(&mut self, value: usize) -> Result<(), &'static str> {
+        if self.control_top >= CONTROL_STACK_SIZE {
+            return Err("Control stack overflow");
+        }
+        self.control_stack[self.control_top] = value;
+        self.control_top += 1;
+        Ok(())
+    }
+
+    fn control_pop(&mut self) -> Result<usize, &'static str> {
+        if self.control_top == 0 {
+            return Err("unstructured control flow");
+        }
+        self.control_top -= 1;
+        Ok(self.control_stack[self.control_top])
+    }
+
+    // Rewrites a previously-emitted placeholder Branch/ZeroBranch with its
+    // resolved target now that the jump destination is known.
+    fn patch_branch_target(&mut self, index: usize, target: usize) {
+        match self.code_arena[index] {
+            Token::ZeroBranch(_) => self.code_arena[index] = Token::ZeroBranch(target),
+            Token::Branch(_) => self.code_arena[index] = Token::Branch(target),
+            _ => {}
+        }
+    }
+
+    fn compile_token(&mut self, token: Token) -> Result<(), &'static str> {
+        if self.code_top >= CODE_ARENA_SIZE {
+            self.abort_compile();
+            return Err("Code arena full");
+        }
+        self.code_arena[self.code_top] = token;
+        self.code_top += 1;
+        Ok(())
+    }
+
+    fn store_name(&mut self, word: &[u8]) -> Result<(usize, usize), &'static str> {
+        if self.names_top + word.len() > NAMES_ARENA_SIZE {
+            return Err("Names arena full");
+        }
+        let start = self.names_top;
+        for &byte in word {
+            self.names_arena[self.names_top] = byte;
+            self.names_top += 1;
+        }
+        Ok((start, word.len()))
+    }
+
+    fn find_word(&self, word: &[u8]) -> Option<usize> {
+        for i in (0..self.dict_top).rev() {
+            let entry = self.dictionary[i];
+            if &self.names_arena[entry.name_start..entry.name_start + entry.name_len] == word {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    fn execute_builtin(&mut self, op: BuiltinOp) -> Result<bool, &'static str> {
+        match op {
+            BuiltinOp::Add => {
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                self.stack.push(a + b)?;
+            }
+            BuiltinOp::Sub => {
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                self.stack.push(a - b)?;
+            }
+            BuiltinOp::Mul => {
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                self.stack.push(a * b)?;
+            }
+            BuiltinOp::Div => {
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                if b == 0 {
+                    return Err("Division by zero");
+                }
+                self.stack.push(a / b)?;
+            }
+            BuiltinOp::Mod => {
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                if b == 0 {
+                    return Err("Division by zero");
+                }
+                self.stack.push(a % b)?;
+            }
+            BuiltinOp::Dup => {
+                let a = self.stack.peek()?;
+                self.stack.push(a)?;
+            }
+            BuiltinOp::Drop => {
+                self.stack.pop()?;
+            }
+            BuiltinOp::Swap => {
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                self.stack.push(b)?;
+                self.stack.push(a)?;
+            }
+            BuiltinOp::Over => {
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                self.stack.push(a)?;
+                self.stack.push(b)?;
+                self.stack.push(a)?;
+            }
+            BuiltinOp::Rot => {
+                let c = self.stack.pop()?;
+                let b = self.stack.pop()?;
+                let a = self.stack.pop()?;
+                self.stack.push(b)?;
+                self.stack.push(c)?;
+                self.stack.push(a)?;
+            }
+            BuiltinOp::Dot => {
+                let value = self.stack.pop()?;
+                print_num(value);
+                print_str(" ");
+            }
+            BuiltinOp::DotS => {
+                print_str("<");
+                print_num(self.stack.size() as i32);
+                print_str("> ");
+                for i in 0..self.stack.size() {
+                    print_num(self.stack.data[i]);
+                    print_str(" ");
+                }
+            }
+            BuiltinOp::Cr => {
+                print_str("\n");
+            }
+            BuiltinOp::Bye => {
+                print_str("Goodbye!\n");
+                return Ok(true);
+            }
+            BuiltinOp::I => {
+                let index = self.return_stack.peek()?;
+                self.stack.push(index)?;
+            }
+        }
+        Ok(false)
+    }
+
+    // Walks a user word's compiled token list with a fixed return stack of
+    // (resume ip, caller entry index) pairs, so nested and recursive
+    // calls don't need the native call stack or heap allocation.
+    fn execute_user_word(&mut self, start_entry_index: usize) -> Result<bool, &'static str> {
+        let mut call_stack: [(usize, usize); CALL_STACK_SIZE] = [(0, 0); CALL_STACK_SIZE];
+        let mut depth = 0usize;
+        let mut entry_index = start_entry_index;
+        let mut ip = self.dictionary[entry_index].code_start;
+
+        loop {
+            let entry = self.dictionary[entry_index];
+            let end = entry.code_start + entry.code_len;
+
+            if ip >= end {
+                if depth == 0 {
+                    return Ok(false);
+                }
+                depth -= 1;
+                let (return_ip, return_entry) = call_stack[depth];
+                ip = return_ip;
+                entry_index = return_entry;
+                continue;
+            }
+
+            match self.code_arena[ip] {
+                Token::Literal(value) => {
+                    self.stack.push(value)?;
+                    ip += 1;
+                }
+                Token::Builtin(op) => {
+                    let should_exit = self.execute_builtin(op)?;
+                    ip += 1;
+                    if should_exit {
+                        return Ok(true);
+                    }
+                }
+                Token::Call(callee_index) => {
+                    if depth >= CALL_STACK_SIZE {
+                        return Err("Call stack overflow");
+                    }
+                    call_stack[depth] = (ip + 1, entry_index);
+                    depth += 1;
+                    entry_index = callee_index;
+                    ip = self.dictionary[entry_index].code_start;
+                }
+                Token::Branch(target) => {
+                    ip = target;
+                }
+                Token::ZeroBranch(target) => {
+                    let flag = self.stack.pop()?;
+                    ip = if flag == 0 { target } else { ip + 1 };
+                }
+                Token::Do => {
+                    let start = self.stack.pop()?;
+                    let limit = self.stack.pop()?;
+                    self.return_stack.push(limit)?;
+                    self.return_stack.push(start)?;
+                    ip += 1;
+                }
+                Token::Loop(target) => {
+                    let index = self.return_stack.pop()?;
+                    let limit = self.return_stack.pop()?;
+                    let next_index = index + 1;
+                    if next_index >= limit {
+                        ip += 1;
+                    } else {
+                        self.return_stack.push(limit)?;
+                        self.return_stack.push(next_index)?;
+                        ip = target;
+                    }
+                }
+            }
+        }
+    }
+
     fn read_line(&mut self) -> bool {
         let mut pos = 0;
         
@@ -267,105 +756,184 @@ impl ForthInterpreter {
         Some(result)
     }
     
-    fn word_matches(word: &[u8], target: &[u8]) -> bool {
-        if word.len() != target.len() {
-            return false;
-        }
-        
-        for i in 0..word.len() {
-            if word[i] != target[i] {
-                return false;
-            }
-        }
-        
-        true
-    }
-    
     fn execute_word(&mut self, word: &[u8]) -> Result<bool, &'static str> {
-        // Try to parse as number first
-        if let Some(num) = Self::parse_number(word) {
-            self.stack.push(num)?;
+        // The word right after `:` names the definition, regardless of
+        // what it looks like (it never gets parsed/looked up as usual).
+        if self.compiling && self.defining_name {
+            let (name_start, name_len) = match self.store_name(word) {
+                Ok(v) => v,
+                Err(e) => {
+                    self.abort_compile();
+                    return Err(e);
+                }
+            };
+            if self.dict_top >= DICTIONARY_SIZE {
+                self.abort_compile();
+                return Err("Dictionary full");
+            }
+            self.dictionary[self.dict_top] = Entry {
+                name_start,
+                name_len,
+                code_start: self.current_def_code_start,
+                code_len: 0,
+            };
+            self.current_def_index = self.dict_top;
+            self.dict_top += 1;
+            self.defining_name = false;
             return Ok(false);
         }
-        
-        // Execute built-in words
-        if Self::word_matches(word, b"+") {
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            self.stack.push(a + b)?;
-        } else if Self::word_matches(word, b"-") {
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            self.stack.push(a - b)?;
-        } else if Self::word_matches(word, b"*") {
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            self.stack.push(a * b)?;
-        } else if Self::word_matches(word, b"/") {
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            if b == 0 {
-                return Err("Division by zero");
-            }
-            self.stack.push(a / b)?;
-        } else if Self::word_matches(word, b"mod") {
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            if b == 0 {
-                return Err("Division by zero");
-            }
-            self.stack.push(a % b)?;
-        } else if Self::word_matches(word, b"dup") {
-            let a = self.stack.peek()?;
-            self.stack.push(a)?;
-        } else if Self::word_matches(word, b"drop") {
-            self.stack.pop()?;
-        } else if Self::word_matches(word, b"swap") {
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            self.stack.push(b)?;
-            self.stack.push(a)?;
-        } else if Self::word_matches(word, b"over") {
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            self.stack.push(a)?;
-            self.stack.push(b)?;
-            self.stack.push(a)?;
-        } else if Self::word_matches(word, b"rot") {
-            let c = self.stack.pop()?;
-            let b = self.stack.pop()?;
-            let a = self.stack.pop()?;
-            self.stack.push(b)?;
-            self.stack.push(c)?;
-            self.stack.push(a)?;
-        } else if Self::word_matches(word, b".") {
-            let value = self.stack.pop()?;
-            print_num(value);
-            print_str(" ");
-        } else if Self::word_matches(word, b".s") {
-            print_str("<");
-            print_num(self.stack.size() as i32);
-            print_str("> ");
-            for i in 0..self.stack.size() {
-                print_num(self.stack.data[i]);
-                print_str(" ");
+
+        if self.compiling {
+            if matches!(word, b":") {
+                return Err("Already compiling");
             }
-        } else if Self::word_matches(word, b"cr") {
-            print_str("\n");
-        } else if Self::word_matches(word, b"bye") {
-            print_str("Goodbye!\n");
-            return Ok(true);
-        } else {
+            if matches!(word, b";") {
+                if self.control_top != 0 {
+                    self.abort_compile();
+                    return Err("unstructured control flow");
+                }
+                self.dictionary[self.current_def_index].code_len =
+                    self.code_top - self.current_def_code_start;
+                self.compiling = false;
+                return Ok(false);
+            }
+            if matches!(word, b"if") {
+                let zbranch_index = self.code_top;
+                if let Err(e) = self.compile_token(Token::ZeroBranch(0)) {
+                    return Err(e);
+                }
+                if let Err(e) = self.control_push(zbranch_index) {
+                    self.abort_compile();
+                    return Err(e);
+                }
+                return Ok(false);
+            }
+            if matches!(word, b"else") {
+                let if_index = match self.control_pop() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        self.abort_compile();
+                        return Err(e);
+                    }
+                };
+                let branch_index = self.code_top;
+                if let Err(e) = self.compile_token(Token::Branch(0)) {
+                    return Err(e);
+                }
+                self.patch_branch_target(if_index, self.code_top);
+                if let Err(e) = self.control_push(branch_index) {
+                    self.abort_compile();
+                    return Err(e);
+                }
+                return Ok(false);
+            }
+            if matches!(word, b"then") {
+                let pending_index = match self.control_pop() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        self.abort_compile();
+                        return Err(e);
+                    }
+                };
+                self.patch_branch_target(pending_index, self.code_top);
+                return Ok(false);
+            }
+            if matches!(word, b"begin") {
+                if let Err(e) = self.control_push(self.code_top) {
+                    self.abort_compile();
+                    return Err(e);
+                }
+                return Ok(false);
+            }
+            if matches!(word, b"until") {
+                let target = match self.control_pop() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        self.abort_compile();
+                        return Err(e);
+                    }
+                };
+                if let Err(e) = self.compile_token(Token::ZeroBranch(target)) {
+                    return Err(e);
+                }
+                return Ok(false);
+            }
+            if matches!(word, b"do") {
+                if let Err(e) = self.compile_token(Token::Do) {
+                    return Err(e);
+                }
+                if let Err(e) = self.control_push(self.code_top) {
+                    self.abort_compile();
+                    return Err(e);
+                }
+                return Ok(false);
+            }
+            if matches!(word, b"loop") {
+                let target = match self.control_pop() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        self.abort_compile();
+                        return Err(e);
+                    }
+                };
+                if let Err(e) = self.compile_token(Token::Loop(target)) {
+                    return Err(e);
+                }
+                return Ok(false);
+            }
+            if let Some(num) = Self::parse_number(word) {
+                self.compile_token(Token::Literal(num))?;
+                return Ok(false);
+            }
+            if let Some(op) = lookup_builtin(word) {
+                self.compile_token(Token::Builtin(op))?;
+                return Ok(false);
+            }
+            if let Some(index) = self.find_word(word) {
+                self.compile_token(Token::Call(index))?;
+                return Ok(false);
+            }
+            self.abort_compile();
             print_str("Unknown word: ");
             let word_str = unsafe { core::str::from_utf8_unchecked(word) };
             print_str(word_str);
             print_str("\n");
             return Err("Unknown word");
         }
-        
-        Ok(false)
+
+        if matches!(word, b":") {
+            self.compiling = true;
+            self.defining_name = true;
+            self.current_def_code_start = self.code_top;
+            return Ok(false);
+        }
+        if matches!(word, b";") {
+            return Err("Not compiling");
+        }
+        if is_control_word(word) {
+            return Err("control flow words are only valid inside a definition");
+        }
+
+        if let Some(num) = Self::parse_number(word) {
+            self.stack.push(num)?;
+            return Ok(false);
+        }
+
+        if let Some(op) = lookup_builtin(word) {
+            return self.execute_builtin(op);
+        }
+
+        if let Some(index) = self.find_word(word) {
+            return self.execute_user_word(index);
+        }
+
+        print_str("Unknown word: ");
+        let word_str = unsafe { core::str::from_utf8_unchecked(word) };
+        print_str(word_str);
+        print_str("\n");
+        Err("Unknown word")
     }
-    
+
     fn process_line(&mut self) -> Result<bool, &'static str> {
         let mut pos = 0;
         
@@ -422,15 +990,22 @@ impl ForthInterpreter {
     fn run(&mut self) {
         print_str("Mini Forth Interpreter v0.1\n");
         print_str("Type 'bye' to exit, '.s' to show stack\n");
-        print_str("Available words: + - * / mod dup drop swap over rot . .s cr bye\n\n");
-        
+        print_str("Available words: + - * / mod dup drop swap over rot . .s cr bye i\n");
+        print_str("Define words with : name ... ;\n");
+        print_str("Control flow: if/else/then  begin/until  do/loop\n\n");
+
         loop {
-            print_str("ok> ");
-            
+            print_str(if self.compiling { ": " } else { "ok> " });
+            flush_output();
+
             if !self.read_line() {
+                if self.compiling {
+                    print_str("Error: unterminated definition at end of input\n");
+                    self.abort_compile();
+                }
                 break;
             }
-            
+
             match self.process_line() {
                 Ok(should_exit) => {
                     if should_exit {
@@ -452,19 +1027,13 @@ impl ForthInterpreter {
 pub extern "C" fn _start() -> ! {
     let mut interpreter = ForthInterpreter::new();
     interpreter.run();
-    
-    unsafe {
-        syscalls::syscall1(syscalls::SYS_EXIT, 0);
-    }
-    
-    loop {}
+
+    platform::sys_exit(0);
 }
 
 #[panic_handler]
 fn panic(_info: &PanicInfo) -> ! {
     print_str("\nPanic occurred!\n");
-    unsafe {
-        syscalls::syscall1(syscalls::SYS_EXIT, 1);
-    }
-    loop {}
+    flush_output();
+    platform::sys_exit(1);
 }